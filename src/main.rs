@@ -1,13 +1,22 @@
 use clap::{Parser, ValueHint};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+#[cfg(unix)]
+use nix::sys::signal::{self, Signal};
+#[cfg(unix)]
+use nix::unistd::Pid;
+use notify::event::ModifyKind;
 use notify::Watcher;
 use std::fs;
 use std::io::BufRead;
 use std::io::BufReader;
 use std::path::Path;
+use std::path::PathBuf;
 use std::process::Child;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::Duration;
+use std::time::Instant;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -18,9 +27,14 @@ struct Args {
     /// File containing list of files to watch
     #[arg(short = 'l', long = "list")]
     list: Option<String>,
-    /// Interval between two child process to start, in milliseconds
+    /// Minimum gap to leave between two change-triggered runs, in milliseconds. Unlike the
+    /// debounce window this never drops a coalesced batch of changes, it only delays running it.
     #[arg(short = 'i', long = "interval", default_value = "1000")]
     interval: u64,
+    /// Debounce window, in milliseconds: wait this long after the last change before running,
+    /// coalescing a burst of changes into a single run instead of dropping the later ones
+    #[arg(short = 'd', long = "debounce", default_value = "100")]
+    debounce: u64,
     /// Re-execute command on file change
     #[arg(short = 'r', long = "reexec")]
     reexec: bool,
@@ -30,47 +44,327 @@ struct Args {
     /// Do not print anything except errors
     #[arg(short = 'q', long = "quiet")]
     quiet: bool,
+    /// Glob pattern for paths to ignore, gitignore-style: a bare name matches at any depth, a
+    /// name containing `/` is anchored to the current directory (repeatable)
+    #[arg(long = "ignore")]
+    ignore: Vec<String>,
+    /// Glob pattern a path must match to trigger a run, gitignore-style (see --ignore); if none
+    /// are given, every path matches (repeatable)
+    #[arg(long = "filter")]
+    filter: Vec<String>,
+    /// Signal to send the previous child before force-killing it (Unix only, e.g. SIGTERM, SIGINT)
+    #[arg(long = "signal", default_value = "SIGTERM")]
+    signal: String,
+    /// How long to wait after the graceful signal before force-killing the child, in milliseconds
+    #[arg(long = "kill-timeout", default_value = "5000")]
+    kill_timeout: u64,
+    /// Comma-separated list of event kinds that trigger a run: modify,create,remove,attrib
+    #[arg(long = "on", default_value = "modify")]
+    on: String,
+    /// Clear the terminal before each run
+    #[arg(short = 'c', long = "clear")]
+    clear: bool,
+    /// Restart the command if it exits on its own (not due to a file change), backing off
+    /// exponentially between crash-loop restarts
+    #[arg(long = "restart")]
+    restart: bool,
     /// Command to run
     #[arg(required = true, num_args(1..), value_hint = ValueHint::CommandWithArguments, trailing_var_arg(true))]
     command: Vec<String>,
 }
 
+/// Expand one ignore/filter glob into the concrete `globset` patterns needed to match it the
+/// way `.gitignore` would against an absolute `event.paths` entry:
+/// - a bare name (no `/`, besides a possible trailing one) matches at any depth, so it's
+///   prefixed with `**/`;
+/// - a name containing a `/` (other than a trailing one) is anchored, so it's rooted at `root`
+///   (the directory the pattern was declared relative to);
+/// - either way, a second pattern with a trailing `/**` is added so the glob also matches
+///   everything *inside* the path, the way gitignore treats a matched directory.
+fn expand_glob_pattern(pattern: &str, root: &Path) -> Vec<String> {
+    let body = pattern.trim_end_matches('/');
+    let anchored = body.starts_with('/') || body.contains('/');
+    let body = body.trim_start_matches('/');
+    let base = if anchored {
+        format!("{}/{}", root.display(), body)
+    } else {
+        format!("**/{}", body)
+    };
+    vec![base.clone(), format!("{}/**", base)]
+}
+
+/// Walk up from `start` looking for the nearest `.gitignore` and return its patterns, expanded
+/// relative to the directory it was found in. Blank lines and `#` comments are skipped.
+fn discover_gitignore_patterns(start: &Path) -> Vec<String> {
+    for dir in start.ancestors() {
+        let candidate = dir.join(".gitignore");
+        let Ok(file) = fs::File::open(&candidate) else {
+            continue;
+        };
+        return BufReader::new(file)
+            .lines()
+            .map_while(Result::ok)
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .flat_map(|pattern| expand_glob_pattern(&pattern, dir))
+            .collect();
+    }
+    Vec::new()
+}
+
+fn build_globset(patterns: &[String]) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        match Glob::new(pattern) {
+            Ok(glob) => {
+                builder.add(glob);
+            }
+            Err(e) => eprintln!("Invalid glob pattern {:?}: {}", pattern, e),
+        }
+    }
+    builder.build().unwrap_or_else(|e| {
+        eprintln!("Failed to compile glob set: {}", e);
+        GlobSetBuilder::new().build().unwrap()
+    })
+}
+
+bitflags::bitflags! {
+    /// Which notify event kinds should trigger a run.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct EventKinds: u8 {
+        const MODIFY = 0b0001;
+        const CREATE = 0b0010;
+        const REMOVE = 0b0100;
+        const ATTRIB = 0b1000;
+    }
+}
+
+/// A coalesced change, carrying enough of the originating `notify::Event` to describe
+/// to the spawned command what happened and where.
+struct ChangeEvent {
+    kind: notify::EventKind,
+    paths: Vec<PathBuf>,
+}
+
+/// Everything the run loop can wake up for: a batch of file changes, or the current
+/// child exiting on its own (only sent when `--restart` is enabled).
+enum LoopEvent {
+    FileChange(ChangeEvent),
+    ChildExited,
+}
+
+/// Clear the terminal the way `watchexec -c` does.
+fn clear_screen() {
+    print!("\x1B[2J\x1B[1;1H");
+    let _ = std::io::Write::flush(&mut std::io::stdout());
+}
+
+/// Spawn a thread that watches `slot` for the specific child identified by `pid` exiting on
+/// its own, then reports it over `tx`. Exits quietly if `slot` no longer holds that child,
+/// since that means the run loop already replaced or removed it itself.
+fn spawn_exit_watcher(
+    slot: Arc<Mutex<Option<Child>>>,
+    tx: Arc<Mutex<std::sync::mpsc::Sender<LoopEvent>>>,
+    pid: u32,
+) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_millis(200));
+        let mut guard = slot.lock().unwrap();
+        match guard.as_mut() {
+            Some(child) if child.id() == pid => match child.try_wait() {
+                Ok(Some(_status)) => {
+                    *guard = None;
+                    drop(guard);
+                    let _ = tx.lock().unwrap().send(LoopEvent::ChildExited);
+                    return;
+                }
+                Ok(None) => continue,
+                Err(_) => return,
+            },
+            _ => return,
+        }
+    });
+}
+
+/// Render an event kind as the short word used in `--on` and `WATCHDOG_EVENT_KIND`.
+fn event_kind_label(kind: &notify::EventKind) -> &'static str {
+    match kind {
+        notify::EventKind::Modify(ModifyKind::Metadata(_)) => "attrib",
+        notify::EventKind::Modify(_) => "modify",
+        notify::EventKind::Create(_) => "create",
+        notify::EventKind::Remove(_) => "remove",
+        _ => "other",
+    }
+}
+
+/// Parse a comma list like `modify,create,remove,attrib` into an `EventKinds` bitset.
+fn parse_event_kinds(s: &str) -> EventKinds {
+    let mut kinds = EventKinds::empty();
+    for part in s.split(',') {
+        match part.trim() {
+            "modify" => kinds |= EventKinds::MODIFY,
+            "create" => kinds |= EventKinds::CREATE,
+            "remove" => kinds |= EventKinds::REMOVE,
+            "attrib" => kinds |= EventKinds::ATTRIB,
+            "" => {}
+            other => eprintln!("Unknown event kind {:?}, ignoring", other),
+        }
+    }
+    kinds
+}
+
+/// Ask `child` to exit gracefully (`SIGTERM`/`signal_name` on Unix, `Child::kill()` elsewhere),
+/// wait up to `timeout` for it to do so, then force-kill it if it's still alive.
+fn terminate_child(
+    child: &mut Child,
+    signal_name: &str,
+    timeout: Duration,
+    quiet: bool,
+) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        match Signal::from_str(signal_name) {
+            Ok(sig) => {
+                if let Err(e) = signal::kill(Pid::from_raw(child.id() as i32), sig) {
+                    eprintln!("Failed to send {} to child: {}", signal_name, e);
+                }
+            }
+            Err(_) => {
+                eprintln!("Unknown signal {:?}, falling back to kill", signal_name);
+                child.kill()?;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = signal_name;
+        child.kill()?;
+    }
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if child.try_wait()?.is_some() {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    if !quiet {
+        println!("Child did not exit within {:?}, force killing", timeout);
+    }
+    child.kill()?;
+    child.wait()?;
+    Ok(())
+}
+
+/// Install a Ctrl-C/SIGTERM handler that forwards the same signal to the running
+/// child (if any) and then exits, instead of leaving the child orphaned.
+#[cfg(unix)]
+fn install_signal_forwarder(child: Arc<Mutex<Option<Child>>>, quiet: bool) {
+    std::thread::spawn(move || {
+        let mut signals = signal_hook::iterator::Signals::new([
+            signal_hook::consts::SIGINT,
+            signal_hook::consts::SIGTERM,
+        ])
+        .expect("Failed to install signal handler");
+        if let Some(sig) = signals.forever().next() {
+            if !quiet {
+                println!("Received signal {}, forwarding to child", sig);
+            }
+            if let Some(mut child) = child.lock().unwrap().take() {
+                if let Ok(sig) = Signal::try_from(sig) {
+                    let _ = signal::kill(Pid::from_raw(child.id() as i32), sig);
+                }
+                let _ = child.wait();
+            }
+            std::process::exit(0);
+        }
+    });
+}
+
+#[cfg(not(unix))]
+fn install_signal_forwarder(_child: Arc<Mutex<Option<Child>>>, _quiet: bool) {}
+
 fn main() {
     let args = Args::parse();
     let files: Vec<String> = {
         if let Some(list) = args.list {
             let file = fs::File::open(list).expect("Failed to open file");
             let reader = BufReader::new(file);
-            let files: Vec<String> = reader.lines().filter_map(|line| line.ok()).collect();
+            let files: Vec<String> = reader.lines().map_while(Result::ok).collect();
             files
         } else {
             args.files
         }
     };
-    if files.len() == 0 {
+    if files.is_empty() {
         eprintln!("No files to watch");
         std::process::exit(1);
     }
 
     let interval = args.interval;
+    let debounce = Duration::from_millis(args.debounce);
+
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let mut ignore_patterns: Vec<String> = args
+        .ignore
+        .iter()
+        .flat_map(|pattern| expand_glob_pattern(pattern, &cwd))
+        .collect();
+    for file in &files {
+        let start = Path::new(file).parent().unwrap_or(Path::new("."));
+        ignore_patterns.extend(discover_gitignore_patterns(start));
+    }
+    let ignore_set = build_globset(&ignore_patterns);
+    let filter_patterns: Vec<String> = args
+        .filter
+        .iter()
+        .flat_map(|pattern| expand_glob_pattern(pattern, &cwd))
+        .collect();
+    let filter_set = build_globset(&filter_patterns);
+    let filter_is_empty = args.filter.is_empty();
+    let on_kinds = parse_event_kinds(&args.on);
 
-    let (tx, rx) = std::sync::mpsc::channel::<u64>();
+    let (tx, rx) = std::sync::mpsc::channel::<LoopEvent>();
     let tx_ = Arc::new(Mutex::new(tx));
+    let tx_for_exit = tx_.clone();
     let mut watcher =
         notify::recommended_watcher(move |res: notify::Result<notify::event::Event>| {
             let tx = tx_.clone();
             match res {
-                Ok(event) => match event.kind {
-                    notify::EventKind::Modify(_) => {
-                        let lock = tx.lock().unwrap();
-                        _ = lock.send(1);
+                Ok(event) => {
+                    let triggers = match event.kind {
+                        notify::EventKind::Modify(ModifyKind::Metadata(_)) => {
+                            on_kinds.contains(EventKinds::ATTRIB)
+                        }
+                        notify::EventKind::Modify(_) => on_kinds.contains(EventKinds::MODIFY),
+                        notify::EventKind::Create(_) => on_kinds.contains(EventKinds::CREATE),
+                        notify::EventKind::Remove(_) => on_kinds.contains(EventKinds::REMOVE),
+                        notify::EventKind::Any
+                        | notify::EventKind::Access(_)
+                        | notify::EventKind::Other => false,
+                    };
+                    if triggers {
+                        let paths: Vec<PathBuf> = event
+                            .paths
+                            .iter()
+                            .filter(|path| {
+                                !ignore_set.is_match(path)
+                                    && (filter_is_empty || filter_set.is_match(path))
+                            })
+                            .cloned()
+                            .collect();
+                        if !paths.is_empty() {
+                            let lock = tx.lock().unwrap();
+                            _ = lock.send(LoopEvent::FileChange(ChangeEvent {
+                                kind: event.kind,
+                                paths,
+                            }));
+                        }
                     }
-                    notify::EventKind::Any => (),
-                    notify::EventKind::Access(_) => (),
-                    notify::EventKind::Create(_) => (),
-                    notify::EventKind::Remove(_) => (),
-                    notify::EventKind::Other => (),
-                },
+                }
                 Err(e) => eprintln!("watch error: {:?}", e),
             }
         })
@@ -90,7 +384,7 @@ fn main() {
     for arg in cmd {
         command.arg(arg);
     }
-    let mut child: Option<Child> = if args.reexec {
+    let initial_child = if args.reexec {
         command
             .spawn()
             .inspect(|_| {
@@ -103,49 +397,245 @@ fn main() {
     } else {
         None
     };
+    let child: Arc<Mutex<Option<Child>>> = Arc::new(Mutex::new(initial_child));
+    install_signal_forwarder(child.clone(), args.quiet);
+    if args.restart {
+        if let Some(pid) = child.lock().unwrap().as_ref().map(Child::id) {
+            spawn_exit_watcher(child.clone(), tx_for_exit.clone(), pid);
+        }
+    }
+
+    const RESTART_BACKOFF_INITIAL: Duration = Duration::from_millis(200);
+    const RESTART_BACKOFF_CAP: Duration = Duration::from_secs(30);
+    let mut restart_backoff = RESTART_BACKOFF_INITIAL;
+    let mut spawned_at = Instant::now();
+
     loop {
-        rx.recv().unwrap();
-        let now = std::time::Instant::now();
-        if now - last > Duration::from_millis(interval) {
-            last = now;
+        let mut events = Vec::new();
+        let mut restart_pending = false;
+        match rx.recv().unwrap() {
+            LoopEvent::FileChange(event) => events.push(event),
+            LoopEvent::ChildExited => restart_pending = true,
+        }
+        if !restart_pending {
+            // Trailing-edge debounce: every further event within the window resets the wait, so
+            // the command fires once, `debounce` after the *last* change in a burst. A child
+            // exit seen while draining a burst doesn't need separate handling: the spawn below
+            // replaces whatever is in `child` regardless of whether it died on its own.
+            while let Ok(next) = rx.recv_timeout(debounce) {
+                if let LoopEvent::FileChange(event) = next {
+                    events.push(event);
+                }
+            }
+        }
+
+        if restart_pending {
+            if spawned_at.elapsed() > RESTART_BACKOFF_CAP {
+                restart_backoff = RESTART_BACKOFF_INITIAL;
+            }
             if !args.quiet {
-                println!("Change detected");
+                println!("Child exited on its own, restarting in {:?}", restart_backoff);
             }
-            if let Some(mut prev_child) = child.take() {
-                if args.kill && args.reexec {
-                    if prev_child
-                        .kill()
-                        .inspect_err(|e| eprintln!("Failed to kill child: {}", e))
-                        .is_ok()
-                        && prev_child
-                            .wait()
-                            .inspect_err(|e| eprintln!("Failed to finalize child: {}", e))
-                            .is_ok()
-                    {
-                        if !args.quiet {
-                            println!("Child process terminated");
-                        }
-                    } else {
-                        eprintln!("Failed to kill child process, skipping re-execution");
+            std::thread::sleep(restart_backoff);
+            restart_backoff = (restart_backoff * 2).min(RESTART_BACKOFF_CAP);
+            if args.clear {
+                clear_screen();
+            }
+            command.env("WATCHDOG_EVENT_KIND", "exit");
+            command.env_remove("WATCHDOG_CHANGED_PATH");
+            command.env_remove("WATCHDOG_CHANGED_PATHS");
+            let child_slot = child.clone();
+            let mut child = child.lock().unwrap();
+            *child = command
+                .spawn()
+                .inspect(|_| {
+                    if !args.quiet {
+                        println!("Child process started");
                     }
-                } else {
-                    let exited = prev_child.try_wait().unwrap().is_none();
-                    if !exited {
-                        child = Some(prev_child);
+                })
+                .inspect_err(|e| eprintln!("Failed to start command: {}", e))
+                .ok();
+            spawned_at = Instant::now();
+            if let Some(pid) = child.as_ref().map(Child::id) {
+                spawn_exit_watcher(child_slot, tx_for_exit.clone(), pid);
+            }
+            continue;
+        }
+
+        // `interval` is a minimum gap between runs, not a debounce: never drop the batch we just
+        // coalesced, only delay acting on it so two runs don't start back-to-back.
+        let since_last = Instant::now() - last;
+        let min_gap = Duration::from_millis(interval);
+        if since_last < min_gap {
+            std::thread::sleep(min_gap - since_last);
+        }
+        last = Instant::now();
+        if !args.quiet {
+            println!("Change detected");
+        }
+        if args.clear {
+            clear_screen();
+        }
+        let child_slot = child.clone();
+        let mut child = child.lock().unwrap();
+        if let Some(mut prev_child) = child.take() {
+            if args.kill || args.reexec {
+                if terminate_child(
+                    &mut prev_child,
+                    &args.signal,
+                    Duration::from_millis(args.kill_timeout),
+                    args.quiet,
+                )
+                .is_ok()
+                {
+                    if !args.quiet {
+                        println!("Child process terminated");
                     }
+                } else {
+                    eprintln!("Failed to kill child process, skipping re-execution");
+                    *child = Some(prev_child);
+                }
+            } else {
+                let exited = prev_child.try_wait().unwrap().is_some();
+                if !exited {
+                    *child = Some(prev_child);
                 }
             }
-            if child.is_none() {
-                child = command
-                    .spawn()
-                    .inspect(|_| {
-                        if !args.quiet {
-                            println!("Child process started");
-                        }
-                    })
-                    .inspect_err(|e| eprintln!("Failed to start command: {}", e))
-                    .ok();
+        }
+        if child.is_none() {
+            let mut kinds: Vec<&str> = events.iter().map(|e| event_kind_label(&e.kind)).collect();
+            kinds.sort_unstable();
+            kinds.dedup();
+            command.env("WATCHDOG_EVENT_KIND", kinds.join(","));
+            let mut paths = events.iter().flat_map(|event| event.paths.iter());
+            if let Some(first_path) = paths.next() {
+                command.env("WATCHDOG_CHANGED_PATH", first_path);
+            }
+            let changed_paths = events
+                .iter()
+                .flat_map(|event| event.paths.iter())
+                .map(|path| path.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(":");
+            command.env("WATCHDOG_CHANGED_PATHS", changed_paths);
+            *child = command
+                .spawn()
+                .inspect(|_| {
+                    if !args.quiet {
+                        println!("Child process started");
+                    }
+                })
+                .inspect_err(|e| eprintln!("Failed to start command: {}", e))
+                .ok();
+            spawned_at = Instant::now();
+            if args.restart {
+                if let Some(pid) = child.as_ref().map(Child::id) {
+                    spawn_exit_watcher(child_slot, tx_for_exit.clone(), pid);
+                }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_glob_pattern_bare_name_matches_any_depth() {
+        let root = Path::new("/repo");
+        let patterns = expand_glob_pattern("target", root);
+        assert_eq!(patterns, vec!["**/target".to_string(), "**/target/**".to_string()]);
+    }
+
+    #[test]
+    fn expand_glob_pattern_anchors_names_with_a_slash() {
+        let root = Path::new("/repo");
+        let patterns = expand_glob_pattern("/target", root);
+        assert_eq!(
+            patterns,
+            vec!["/repo/target".to_string(), "/repo/target/**".to_string()]
+        );
+    }
+
+    #[test]
+    fn expand_glob_pattern_strips_trailing_slash() {
+        let root = Path::new("/repo");
+        let patterns = expand_glob_pattern("target/", root);
+        assert_eq!(patterns, vec!["**/target".to_string(), "**/target/**".to_string()]);
+    }
+
+    #[test]
+    fn build_globset_matches_bare_name_at_any_depth() {
+        let patterns = expand_glob_pattern("target", Path::new("/repo"));
+        let set = build_globset(&patterns);
+        assert!(set.is_match("/x/target"));
+        assert!(set.is_match("/x/target/a"));
+        assert!(!set.is_match("/x/not-target"));
+    }
+
+    #[test]
+    fn build_globset_skips_invalid_patterns() {
+        let set = build_globset(&["[".to_string()]);
+        assert_eq!(set.len(), 0);
+    }
+
+    #[test]
+    fn parse_event_kinds_parses_known_kinds() {
+        let kinds = parse_event_kinds("modify,create");
+        assert!(kinds.contains(EventKinds::MODIFY));
+        assert!(kinds.contains(EventKinds::CREATE));
+        assert!(!kinds.contains(EventKinds::REMOVE));
+        assert!(!kinds.contains(EventKinds::ATTRIB));
+    }
+
+    #[test]
+    fn parse_event_kinds_ignores_unknown_kinds() {
+        let kinds = parse_event_kinds("modify, bogus ,remove");
+        assert_eq!(kinds, EventKinds::MODIFY | EventKinds::REMOVE);
+    }
+
+    #[test]
+    fn event_kind_label_maps_known_kinds() {
+        assert_eq!(
+            event_kind_label(&notify::EventKind::Create(notify::event::CreateKind::File)),
+            "create"
+        );
+        assert_eq!(
+            event_kind_label(&notify::EventKind::Remove(notify::event::RemoveKind::File)),
+            "remove"
+        );
+        assert_eq!(
+            event_kind_label(&notify::EventKind::Modify(ModifyKind::Metadata(
+                notify::event::MetadataKind::Any
+            ))),
+            "attrib"
+        );
+        assert_eq!(
+            event_kind_label(&notify::EventKind::Modify(ModifyKind::Data(
+                notify::event::DataChange::Any
+            ))),
+            "modify"
+        );
+        assert_eq!(event_kind_label(&notify::EventKind::Any), "other");
+    }
+
+    #[test]
+    fn discover_gitignore_patterns_finds_nearest_gitignore() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(".gitignore"), "# comment\n\ntarget\n/dist\n").unwrap();
+        let nested = dir.path().join("a/b");
+        fs::create_dir_all(&nested).unwrap();
+
+        let patterns = discover_gitignore_patterns(&nested);
+
+        assert!(patterns.contains(&"**/target".to_string()));
+        assert!(patterns.contains(&format!("{}/dist", dir.path().display())));
+    }
+
+    #[test]
+    fn discover_gitignore_patterns_returns_empty_without_a_gitignore() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(discover_gitignore_patterns(dir.path()).is_empty());
+    }
+}